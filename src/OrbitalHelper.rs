@@ -10,6 +10,203 @@ use alloc::vec::Vec;
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::{alloy_primitives::U256, prelude::*, storage::StorageU256};
 use alloy_primitives::aliases::U144;
+use alloy_primitives::ruint::Uint;
+use alloy_sol_types::sol;
+
+// Wide intermediate used to carry a*b (up to ~2^288) without wrapping inside a
+// 256-bit type before it is shifted/divided back down to Q96X48 / U144.
+type U512 = Uint<512, 8>;
+
+// Upper bound on the root degree `nth_root_Q96X48` will compute. The orbital
+// math only ever needs cube/quartic roots. Without a cap, the radicand shift
+// `48 * (k - 1)` plus up to 144 bits of `v` would overflow the 512-bit
+// intermediate from k = 9 on and silently drop high bits instead of
+// reverting, and the `k - 1` per-iteration full-width multiplications in
+// `u512_pow` would let a caller burn unbounded gas with a large `k`.
+const MAX_ROOT_K: u32 = 4;
+
+sol! {
+    error Overflow();
+    error DivisionByZero();
+    error Underflow();
+    error NoRealRoot();
+    error InvalidLength();
+    error InvalidIndex();
+    error InvalidRoot();
+}
+
+/// Typed revert reasons for the Q96X48 math below, so a failed swap/tick
+/// computation reverts with an ABI-encoded error instead of a bare panic.
+#[derive(SolidityError)]
+pub enum OrbitalError {
+    Overflow(Overflow),
+    DivisionByZero(DivisionByZero),
+    Underflow(Underflow),
+    NoRealRoot(NoRealRoot),
+    InvalidLength(InvalidLength),
+    InvalidIndex(InvalidIndex),
+    InvalidRoot(InvalidRoot),
+}
+
+impl OrbitalError {
+    fn overflow() -> Self {
+        OrbitalError::Overflow(Overflow {})
+    }
+    fn division_by_zero() -> Self {
+        OrbitalError::DivisionByZero(DivisionByZero {})
+    }
+    fn underflow() -> Self {
+        OrbitalError::Underflow(Underflow {})
+    }
+    fn no_real_root() -> Self {
+        OrbitalError::NoRealRoot(NoRealRoot {})
+    }
+    fn invalid_index() -> Self {
+        OrbitalError::InvalidIndex(InvalidIndex {})
+    }
+    fn invalid_length() -> Self {
+        OrbitalError::InvalidLength(InvalidLength {})
+    }
+    fn invalid_root() -> Self {
+        OrbitalError::InvalidRoot(InvalidRoot {})
+    }
+}
+
+/// A signed Q96X48 fixed-point number: a `U144` magnitude paired with a sign
+/// bit. Lets intermediate results that can go negative (quadratic
+/// coefficients, Newton residuals) be represented faithfully instead of
+/// being clamped to zero by an unsigned subtraction. Zero is always stored
+/// with `negative = false` so `PartialEq`/`PartialOrd` don't need to special
+/// case it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedQ96X48 {
+    magnitude: U144,
+    negative: bool,
+}
+
+impl SignedQ96X48 {
+    pub const ZERO: SignedQ96X48 = SignedQ96X48 {
+        magnitude: U144::ZERO,
+        negative: false,
+    };
+
+    pub fn from_magnitude(magnitude: U144, negative: bool) -> Self {
+        SignedQ96X48 {
+            magnitude,
+            negative: negative && magnitude != U144::ZERO,
+        }
+    }
+
+    pub fn from_unsigned(value: U144) -> Self {
+        SignedQ96X48::from_magnitude(value, false)
+    }
+
+    // Builds a ± Q96X48 value from two unsigned operands, i.e. `a - b`
+    // without ever underflowing the unsigned `sub_Q96X48`.
+    pub fn diff(a: U144, b: U144) -> Result<Self, OrbitalError> {
+        if a >= b {
+            Ok(SignedQ96X48::from_magnitude(
+                OrbitalHelper::sub_Q96X48(a, b)?,
+                false,
+            ))
+        } else {
+            Ok(SignedQ96X48::from_magnitude(
+                OrbitalHelper::sub_Q96X48(b, a)?,
+                true,
+            ))
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> U144 {
+        self.magnitude
+    }
+
+    pub fn abs(self) -> Self {
+        SignedQ96X48::from_magnitude(self.magnitude, false)
+    }
+
+    // Unsigned magnitude, clamped to zero if this value is negative.
+    pub fn to_unsigned_saturating(self) -> U144 {
+        if self.negative {
+            U144::ZERO
+        } else {
+            self.magnitude
+        }
+    }
+}
+
+impl core::ops::Neg for SignedQ96X48 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        SignedQ96X48::from_magnitude(self.magnitude, !self.negative)
+    }
+}
+
+impl core::ops::Add for SignedQ96X48 {
+    type Output = Result<Self, OrbitalError>;
+    fn add(self, rhs: Self) -> Result<Self, OrbitalError> {
+        if self.negative == rhs.negative {
+            Ok(SignedQ96X48::from_magnitude(
+                OrbitalHelper::add_Q96X48(self.magnitude, rhs.magnitude)?,
+                self.negative,
+            ))
+        } else if self.magnitude >= rhs.magnitude {
+            Ok(SignedQ96X48::from_magnitude(
+                OrbitalHelper::sub_Q96X48(self.magnitude, rhs.magnitude)?,
+                self.negative,
+            ))
+        } else {
+            Ok(SignedQ96X48::from_magnitude(
+                OrbitalHelper::sub_Q96X48(rhs.magnitude, self.magnitude)?,
+                rhs.negative,
+            ))
+        }
+    }
+}
+
+impl core::ops::Sub for SignedQ96X48 {
+    type Output = Result<Self, OrbitalError>;
+    fn sub(self, rhs: Self) -> Result<Self, OrbitalError> {
+        self + (-rhs)
+    }
+}
+
+impl core::ops::Mul for SignedQ96X48 {
+    type Output = Result<Self, OrbitalError>;
+    fn mul(self, rhs: Self) -> Result<Self, OrbitalError> {
+        Ok(SignedQ96X48::from_magnitude(
+            OrbitalHelper::mul_Q96X48(self.magnitude, rhs.magnitude)?,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+impl core::ops::Div for SignedQ96X48 {
+    type Output = Result<Self, OrbitalError>;
+    fn div(self, rhs: Self) -> Result<Self, OrbitalError> {
+        Ok(SignedQ96X48::from_magnitude(
+            OrbitalHelper::div_Q96X48(self.magnitude, rhs.magnitude)?,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+impl core::cmp::PartialOrd for SignedQ96X48 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        if self.negative == other.negative {
+            let cmp = self.magnitude.cmp(&other.magnitude);
+            Some(if self.negative { cmp.reverse() } else { cmp })
+        } else if self.negative {
+            Some(core::cmp::Ordering::Less)
+        } else {
+            Some(core::cmp::Ordering::Greater)
+        }
+    }
+}
 
 // Define some persistent storage using the Solidity ABI.
 // `Counter` will be the entrypoint.
@@ -31,136 +228,195 @@ impl OrbitalHelper {
     pub fn convert_from_Q96X48(value: U144) -> U144 {
         value >> 48
     }
-    pub fn add_Q96X48(a: U144, b: U144) -> U144 {
-        a + b
+    pub fn add_Q96X48(a: U144, b: U144) -> Result<U144, OrbitalError> {
+        a.checked_add(b).ok_or_else(OrbitalError::overflow)
     }
-    pub fn sub_Q96X48(a: U144, b: U144) -> U144 {
-        a - b
+    pub fn sub_Q96X48(a: U144, b: U144) -> Result<U144, OrbitalError> {
+        a.checked_sub(b).ok_or_else(OrbitalError::underflow)
     }
-    pub fn mul_Q96X48(a: U144, b: U144) -> U144 {
-        // (a * b) >> 48
-        let product: U256 = U256::from(a) * U256::from(b);
-        let shifted: U256 = product >> 48;
-        // Check if the result fits in U144 (2^144 - 1)
-        let max_u144 = (U256::from(1u128) << 144) - U256::from(1u128);
-        assert!(shifted <= max_u144, "Overflow in Q96X48 multiplication");
-        // Convert U256 to U144 by taking the lower 144 bits
-        // U144 is represented internally as [u64; 3], so we take the first 2.25 u64s
-        let limbs = shifted.as_limbs();
-        let low = limbs[0];
-        let mid = limbs[1];
-        let high = limbs[2] & 0xFFFF; // Only take lower 16 bits of the third limb (144 - 128 = 16)
-        U144::from_limbs([low, mid, high])
+    pub fn mul_Q96X48(a: U144, b: U144) -> Result<U144, OrbitalError> {
+        // (a * b) >> 48, carried out in a 512-bit intermediate: a and b can each
+        // be up to 2^144 - 1, so the true product can reach ~2^288 and would
+        // silently wrap if formed in a 256-bit type before the shift/range-check
+        // ever ran.
+        let product: U512 = U512::from(a) * U512::from(b);
+        let shifted: U512 = product >> 48;
+        Self::narrow_u512_to_u144(shifted)
     }
 
-    pub fn div_Q96X48(a: U144, b: U144) -> U144 {
+    pub fn div_Q96X48(a: U144, b: U144) -> Result<U144, OrbitalError> {
         // (a << 48) / b
-        assert!(b != U144::ZERO, "Division by zero");
-        let dividend: U256 = U256::from(a) << 48;
-        let result: U256 = dividend / U256::from(b);
-        // Check if the result fits in U144 (2^144 - 1)
-        let max_u144 = (U256::from(1u128) << 144) - U256::from(1u128);
-        assert!(result <= max_u144, "Overflow in Q96X48 division");
-        // Convert U256 to U144 by taking the lower 144 bits
-        // U144 is represented internally as [u64; 3], so we take the first 2.25 u64s
-        let limbs = result.as_limbs();
-        let low = limbs[0];
-        let mid = limbs[1];
-        let high = limbs[2] & 0xFFFF; // Only take lower 16 bits of the third limb (144 - 128 = 16)
-        U144::from_limbs([low, mid, high])
+        if b == U144::ZERO {
+            return Err(OrbitalError::division_by_zero());
+        }
+        let dividend: U512 = U512::from(a) << 48;
+        let result: U512 = dividend / U512::from(b);
+        Self::narrow_u512_to_u144(result)
+    }
+
+    /// Fused `a * b / c` for Q96X48 operands: forms the full 288-bit product in
+    /// a 512-bit intermediate and divides by `c` before narrowing, so callers
+    /// that previously chained `mul_Q96X48` then `div_Q96X48` (rounding and
+    /// range-checking against the 2^144 ceiling twice) now round once with no
+    /// intermediate overflow.
+    pub fn mul_div_Q96X48(a: U144, b: U144, c: U144) -> Result<U144, OrbitalError> {
+        if c == U144::ZERO {
+            return Err(OrbitalError::division_by_zero());
+        }
+        let product: U512 = U512::from(a) * U512::from(b);
+        let result: U512 = product / U512::from(c);
+        Self::narrow_u512_to_u144(result)
     }
 
-    // Square root function for Q96X48 format using Newton's method
+    // Square root function for Q96X48 format using a bit-length-seeded,
+    // guarded Newton iteration: g = (g + m/g) / 2 starting from an initial
+    // guess g0 = 2^⌈bitlen(m)/2⌉ that is guaranteed to overestimate the root,
+    // so the sequence monotonically decreases to the floor root in
+    // O(log bits) steps with no iteration cap needed. The radicand never
+    // exceeds 192 bits, so the floor root always fits U144 - this can't fail.
     pub fn sqrt_Q96X48(y: U144) -> U144 {
         if y == U144::ZERO {
             return U144::ZERO;
         }
 
-        // Convert y to U256 and shift by 48 to account for fixed-point precision
-        let mut z: U256 = U256::from(y) << 48;
+        // m is the radicand in 2^96 scale (y is already Q96X48, i.e. *2^48)
+        let m: U256 = U256::from(y) << 48;
+        let mut g: U256 = U256::from(1u8) << m.bit_len().div_ceil(2);
 
-        // Initial guess
-        let two = U256::from(2u8);
-        let one = U256::from(1u8);
-        let mut x = z / two + one;
+        loop {
+            let next = (g + m / g) / U256::from(2u8);
+            if next >= g {
+                break;
+            }
+            g = next;
+        }
 
-        // Babylonian method loop
-        while x < z {
-            z = x;
-            x = (z + (U256::from(y) << 48) / z) / two;
+        Self::narrow_u256_to_u144(g)
+    }
+
+    // Correctly-rounded variant of `sqrt_Q96X48`: rounds to the nearest
+    // representable Q96X48 value instead of always truncating down.
+    pub fn sqrt_round_Q96X48(y: U144) -> U144 {
+        if y == U144::ZERO {
+            return U144::ZERO;
         }
+        let m: U256 = U256::from(y) << 48;
+        let floor_root = U256::from(Self::sqrt_Q96X48(y));
+        // Round up if m is past the midpoint between floor_root² and (floor_root+1)²
+        let midpoint = floor_root * floor_root + floor_root;
+        let rounded = if m > midpoint {
+            floor_root + U256::from(1u8)
+        } else {
+            floor_root
+        };
+        Self::narrow_u256_to_u144(rounded)
+    }
 
-        // Convert result back to Q96X48
-        let result = z;
+    /// Generalizes `sqrt_Q96X48` to an integer root `k` (1 <= k <= MAX_ROOT_K)
+    /// using the same bit-length-seeded guarded Newton recurrence:
+    /// g = ((k-1)*g + m/g^(k-1)) / k, so callers needing cube/quartic roots
+    /// (e.g. the orbital invariant solver) don't have to fall back to a
+    /// numerical Newton loop over the whole residual function. `k` is capped
+    /// at `MAX_ROOT_K` rather than left open-ended: the radicand shift and
+    /// the per-iteration `u512_pow` cost both scale with `k`, so an
+    /// uncapped `k` is both an unbounded-gas griefing vector and, once the
+    /// shift exceeds the 512-bit intermediate, a silent wrong answer instead
+    /// of a revert.
+    pub fn nth_root_Q96X48(v: U144, k: u32) -> Result<U144, OrbitalError> {
+        if k == 0 || k > MAX_ROOT_K {
+            return Err(OrbitalError::invalid_root());
+        }
+        if v == U144::ZERO {
+            return Ok(U144::ZERO);
+        }
+        if k == 1 {
+            return Ok(v);
+        }
 
-        // Convert U256 -> U144
-        let limbs = result.as_limbs();
-        let low = limbs[0];
-        let mid = limbs[1];
-        let high = limbs[2] & 0xFFFF; // only lower 16 bits for U144
-        U144::from_limbs([low, mid, high])
+        // v is Q96X48 (i.e. *2^48); raising to the k-th root needs the
+        // radicand scaled by 2^(48*(k-1)) to land back on a Q96X48 result.
+        let shift = 48u32 * (k - 1);
+        let m: U512 = U512::from(v) << shift;
+        let k_u512 = U512::from(k);
+        let k_minus_1 = U512::from(k - 1);
+
+        let exponent = m.bit_len().div_ceil(k as usize);
+        let mut g: U512 = U512::from(1u8) << exponent;
+
+        loop {
+            let g_pow_k_minus_1 = Self::u512_pow(g, k - 1);
+            let next = (k_minus_1 * g + m / g_pow_k_minus_1) / k_u512;
+            if next >= g {
+                break;
+            }
+            g = next;
+        }
+
+        Self::narrow_u512_to_u144(g)
     }
 
     // calculate radius from reserves and n
-    pub fn calculate_radius(reserve: U144) -> U144 {
-        let root5 = U144::from(629397181890196u128);
+    pub fn calculate_radius(reserve: U144, n: U144) -> Result<U144, OrbitalError> {
         let one = Self::convert_to_Q96X48(U144::from(1));
-        let denominator = Self::sub_Q96X48(one, Self::div_Q96X48(one, root5));
-        return Self::div_Q96X48(reserve, denominator);
+        let n_q = Self::convert_to_Q96X48(n);
+        let inv_sqrt_n = Self::div_Q96X48(one, Self::sqrt_Q96X48(n_q))?;
+        let denominator = Self::sub_Q96X48(one, inv_sqrt_n)?;
+        Self::div_Q96X48(reserve, denominator)
     }
-    // calculate k from p and r using the formula: k = r√n - r(p+n-1)/√(n(p²+n-1))
-    pub fn calculateK(depeg_limit: U144, radius: U144) -> U144 {
-        // Note: assuming n = 5 based on the context (golden ratio calculations)
-        let n = Self::convert_to_Q96X48(U144::from(5));
+    // calculate k from p, r and n using the formula: k = r√n - r(p+n-1)/√(n(p²+n-1))
+    pub fn calculateK(depeg_limit: U144, radius: U144, n: U144) -> Result<U144, OrbitalError> {
+        let n = Self::convert_to_Q96X48(n);
         let one = Self::convert_to_Q96X48(U144::from(1));
-        
+
         // Calculate √n
         let sqrt_n = Self::sqrt_Q96X48(n);
-        
+
         // Calculate first term: r√n
-        let first_term = Self::mul_Q96X48(radius, sqrt_n);
-        
+        let first_term = Self::mul_Q96X48(radius, sqrt_n)?;
+
         // Calculate p² (depeg_limit is already in Q96X48 format)
-        let p_squared = Self::mul_Q96X48(depeg_limit, depeg_limit);
-        
+        let p_squared = Self::mul_Q96X48(depeg_limit, depeg_limit)?;
+
         // Calculate p + n - 1
-        let p_plus_n_minus_1 = Self::sub_Q96X48(
-            Self::add_Q96X48(depeg_limit, n),
-            one
-        );
-        
+        let p_plus_n_minus_1 = Self::sub_Q96X48(Self::add_Q96X48(depeg_limit, n)?, one)?;
+
         // Calculate p² + n - 1
-        let p_squared_plus_n_minus_1 = Self::sub_Q96X48(
-            Self::add_Q96X48(p_squared, n),
-            one
-        );
-        
+        let p_squared_plus_n_minus_1 = Self::sub_Q96X48(Self::add_Q96X48(p_squared, n)?, one)?;
+
         // Calculate n(p² + n - 1)
-        let n_times_expr = Self::mul_Q96X48(n, p_squared_plus_n_minus_1);
-        
+        let n_times_expr = Self::mul_Q96X48(n, p_squared_plus_n_minus_1)?;
+
         // Calculate √(n(p² + n - 1))
         let sqrt_denominator = Self::sqrt_Q96X48(n_times_expr);
-        
-        // Calculate r(p + n - 1)
-        let numerator_second_term = Self::mul_Q96X48(radius, p_plus_n_minus_1);
-        
+
         // Calculate second term: r(p + n - 1) / √(n(p² + n - 1))
-        let second_term = Self::div_Q96X48(numerator_second_term, sqrt_denominator);
-        
+        let second_term = Self::mul_div_Q96X48(radius, p_plus_n_minus_1, sqrt_denominator)?;
+
         // Calculate final result: r√n - r(p + n - 1) / √(n(p² + n - 1))
         Self::sub_Q96X48(first_term, second_term)
     }
     // return k and r together
-    pub fn getTickParameters(depeg_limit: U144, reserve: U144) -> (U144, U144) {
-        let radius = Self::calculate_radius(reserve);
-        let k = Self::calculateK(depeg_limit, radius);
-        (k, radius)
+    pub fn getTickParameters(
+        depeg_limit: U144,
+        reserve: U144,
+        n: U144,
+    ) -> Result<(U144, U144), OrbitalError> {
+        let radius = Self::calculate_radius(reserve, n)?;
+        let k = Self::calculateK(depeg_limit, radius, n)?;
+        Ok((k, radius))
     }
-    pub fn calculateBoundaryTickS(radius: U144, k: U144) -> U144 {
+    pub fn calculateBoundaryTickS(radius: U144, k: U144, n: U144) -> Result<U144, OrbitalError> {
         // Implement the boundary tick calculation logic here
         // s =  sqrt(r² - (k - r√n)²)
-        let difference = Self::sub_Q96X48(k, Self::mul_Q96X48(radius, Self::sqrt_Q96X48(U144::from(5))));
-        Self::sqrt_Q96X48(Self::sub_Q96X48(Self::mul_Q96X48(radius, radius), Self::mul_Q96X48(difference, difference))) 
+        let n_q = Self::convert_to_Q96X48(n);
+        let sqrt_n = Self::sqrt_Q96X48(n_q);
+        let difference = Self::sub_Q96X48(k, Self::mul_Q96X48(radius, sqrt_n)?)?;
+        let r_squared = Self::mul_Q96X48(radius, radius)?;
+        let difference_squared = Self::mul_Q96X48(difference, difference)?;
+        Ok(Self::sqrt_Q96X48(Self::sub_Q96X48(
+            r_squared,
+            difference_squared,
+        )?))
     }
 
     /// Solves the quadratic invariant equation to find the amount needed to cross a tick boundary
@@ -177,177 +433,122 @@ impl OrbitalHelper {
         token_out_index: U144,
         consolidated_radius: U144,
         k_cross: U144,
-    ) -> U144 {
+        n: U144,
+    ) -> Result<U144, OrbitalError> {
+        if reserves.len() != n.as_limbs()[0] as usize {
+            return Err(OrbitalError::invalid_length());
+        }
         let r = consolidated_radius;
         let token_in_idx = token_in_index.as_limbs()[0] as usize;
         let token_out_idx = token_out_index.as_limbs()[0] as usize;
-        
+        if token_in_idx >= reserves.len() || token_out_idx >= reserves.len() {
+            return Err(OrbitalError::invalid_index());
+        }
+
         // Calculate P = k_cross * r - Σx_i
         let mut sum_reserves = U144::ZERO;
         for &reserve in &reserves {
-            sum_reserves = Self::add_Q96X48(sum_reserves, reserve);
+            sum_reserves = Self::add_Q96X48(sum_reserves, reserve)?;
         }
-        let k_cross_times_r = Self::mul_Q96X48(k_cross, r);
-        let p = Self::sub_Q96X48(k_cross_times_r, sum_reserves);
-        
+        let k_cross_times_r = Self::mul_Q96X48(k_cross, r)?;
+        let p = Self::sub_Q96X48(k_cross_times_r, sum_reserves)?;
+
         // Get x_in and x_out
         let x_in = reserves[token_in_idx];
         let x_out = reserves[token_out_idx];
-        
+
         // Calculate C = r² - Σ(r - x_i)² for i ≠ in, out
-        let r_squared = Self::mul_Q96X48(r, r);
+        let r_squared = Self::mul_Q96X48(r, r)?;
         let mut sum_squared_differences = U144::ZERO;
-        
+
         for (i, &reserve) in reserves.iter().enumerate() {
             if i != token_in_idx && i != token_out_idx {
-                let diff = Self::sub_Q96X48(r, reserve);
-                let diff_squared = Self::mul_Q96X48(diff, diff);
-                sum_squared_differences = Self::add_Q96X48(sum_squared_differences, diff_squared);
+                let diff = Self::sub_Q96X48(r, reserve)?;
+                let diff_squared = Self::mul_Q96X48(diff, diff)?;
+                sum_squared_differences = Self::add_Q96X48(sum_squared_differences, diff_squared)?;
             }
         }
-        
-        let c_term = Self::sub_Q96X48(r_squared, sum_squared_differences);
-        
+
+        // C = r² - Σ(r - x_i)², kept signed since the sum of squares can in
+        // principle exceed r² for a badly-consolidated radius
+        let c_term = SignedQ96X48::diff(r_squared, sum_squared_differences)?;
+
         // Calculate coefficients for the quadratic equation ax² + bx + c = 0
         // Based on the formula from the attachment:
         // a = 1
         // b = A + B = (r - x_out - P) + (-(r - x_in)) = -x_out - P + x_in
         // c = (A² + B² - C) / 2
-        
         let a = Self::convert_to_Q96X48(U144::from(1));
-        
-        // Calculate A = r - x_out - P
-        let r_minus_x_out = Self::sub_Q96X48(r, x_out);
-        let a_term = Self::sub_Q96X48(r_minus_x_out, p);
-        
-        // Calculate B = -(r - x_in) = x_in - r  
-        let b_term = Self::sub_Q96X48(x_in, r);
-        
-        // Calculate b = A + B = (r - x_out - P) + (x_in - r) = x_in - x_out - P
-        // We need to be careful about potential underflows
-        let mut b = U144::ZERO;
-        let mut b_is_positive = true;
-        
-        // Calculate x_in - x_out first
-        if x_in >= x_out {
-            let diff = Self::sub_Q96X48(x_in, x_out);
-            if diff >= p {
-                b = Self::sub_Q96X48(diff, p);
-                b_is_positive = true;
-            } else {
-                b = Self::sub_Q96X48(p, diff);
-                b_is_positive = false;
-            }
-        } else {
-            let diff = Self::sub_Q96X48(x_out, x_in);
-            b = Self::add_Q96X48(diff, p);
-            b_is_positive = false;
-        }
-        
-        // For A and B terms to calculate c:
-        // A = r - x_out - P (already calculated as a_term)
-        // B = x_in - r (already calculated as b_term)
-        
+        let p_signed = SignedQ96X48::from_unsigned(p);
+
+        // A = r - x_out - P
+        let a_term = (SignedQ96X48::diff(r, x_out)? - p_signed)?;
+
+        // B = -(r - x_in) = x_in - r
+        let b_term = SignedQ96X48::diff(x_in, r)?;
+
+        // b = A + B
+        let b = (a_term + b_term)?;
+
         // c = (A² + B² - C) / 2
-        let a_squared = Self::mul_Q96X48(a_term, a_term);
-        let b_squared = Self::mul_Q96X48(b_term, b_term);
-        let numerator = Self::sub_Q96X48(
-            Self::add_Q96X48(a_squared, b_squared),
-            c_term
-        );
+        let a_squared = (a_term * a_term)?;
+        let b_squared = (b_term * b_term)?;
         let two = Self::convert_to_Q96X48(U144::from(2));
-        let c = Self::div_Q96X48(numerator, two);
-        
+        let two_signed = SignedQ96X48::from_unsigned(two);
+        let c = (((a_squared + b_squared)? - c_term)? / two_signed)?;
+
         // Solve quadratic equation: ax² + bx + c = 0
         // Using quadratic formula: x = (-b ± √(b² - 4ac)) / 2a
-        
-        // Calculate discriminant: b² - 4ac
-        let b_squared_for_discriminant = Self::mul_Q96X48(b, b);
-        let four = Self::convert_to_Q96X48(U144::from(4));
-        let four_ac = Self::mul_Q96X48(
-            Self::mul_Q96X48(four, a),
-            c
-        );
-        
-        // Check if discriminant is positive
-        if b_squared_for_discriminant < four_ac {
-            // No real solution, return delta_linear as fallback
-            return delta_linear;
+        let a_signed = SignedQ96X48::from_unsigned(a);
+        let four_signed = SignedQ96X48::from_unsigned(Self::convert_to_Q96X48(U144::from(4)));
+        let b_squared_for_discriminant = (b * b)?;
+        let four_ac = ((four_signed * a_signed)? * c)?;
+        let discriminant = (b_squared_for_discriminant - four_ac)?;
+
+        if discriminant.is_negative() {
+            // No real solution: the quadratic invariant has no valid crossing point
+            return Err(OrbitalError::no_real_root());
         }
-        
-        let discriminant = Self::sub_Q96X48(b_squared_for_discriminant, four_ac);
-        let sqrt_discriminant = Self::sqrt_Q96X48(discriminant);
-        
-        // Calculate roots considering the sign of b
-        let two_a = Self::mul_Q96X48(two, a);
-        
-        let (x1, x2) = if b_is_positive {
-            // b is positive, so -b is negative
-            // x1 = (-b + √discriminant) / 2a = (√discriminant - b) / 2a
-            // x2 = (-b - √discriminant) / 2a = -(b + √discriminant) / 2a
-            let x1 = if sqrt_discriminant >= b {
-                Self::div_Q96X48(
-                    Self::sub_Q96X48(sqrt_discriminant, b),
-                    two_a
-                )
-            } else {
-                U144::ZERO // This root would be negative
-            };
-            
-            // x2 would be negative, so we set it to zero
-            let x2 = U144::ZERO;
-            (x1, x2)
-        } else {
-            // b is negative (stored as positive value), so -b is positive
-            // x1 = (-(-b) + √discriminant) / 2a = (b + √discriminant) / 2a
-            // x2 = (-(-b) - √discriminant) / 2a = (b - √discriminant) / 2a
-            let x1 = Self::div_Q96X48(
-                Self::add_Q96X48(b, sqrt_discriminant),
-                two_a
-            );
-            
-            let x2 = if b >= sqrt_discriminant {
-                Self::div_Q96X48(
-                    Self::sub_Q96X48(b, sqrt_discriminant),
-                    two_a
-                )
-            } else {
-                U144::ZERO // This root would be negative
-            };
-            (x1, x2)
-        };
-        
+
+        let sqrt_discriminant =
+            SignedQ96X48::from_unsigned(Self::sqrt_Q96X48(discriminant.magnitude()));
+        let two_a = (two_signed * a_signed)?;
+        let neg_b = -b;
+
+        // x1 = (-b + √discriminant) / 2a, x2 = (-b - √discriminant) / 2a
+        let x1 = ((neg_b + sqrt_discriminant)? / two_a)?;
+        let x2 = ((neg_b - sqrt_discriminant)? / two_a)?;
+
         // Calculate x1 - P and x2 - P, then return whichever is positive
-        let x1_minus_p = if x1 >= p {
-            Self::sub_Q96X48(x1, p)
-        } else {
-            U144::ZERO // Would be negative
-        };
-        
-        let x2_minus_p = if x2 >= p {
-            Self::sub_Q96X48(x2, p)
-        } else {
-            U144::ZERO // Would be negative
-        };
-        
+        let x1_minus_p = (x1 - p_signed)?;
+        let x2_minus_p = (x2 - p_signed)?;
+        let x1_positive = !x1_minus_p.is_negative() && x1_minus_p != SignedQ96X48::ZERO;
+        let x2_positive = !x2_minus_p.is_negative() && x2_minus_p != SignedQ96X48::ZERO;
+
         // Return the positive result, prioritizing the smaller positive value
-        if x1_minus_p > U144::ZERO && x2_minus_p > U144::ZERO {
-            // Return the smaller positive value for boundary crossing
-            if x1_minus_p <= x2_minus_p { x1_minus_p } else { x2_minus_p }
-        } else if x1_minus_p > U144::ZERO {
-            x1_minus_p
-        } else if x2_minus_p > U144::ZERO {
-            x2_minus_p
+        Ok(if x1_positive && x2_positive {
+            if x1_minus_p <= x2_minus_p {
+                x1_minus_p.magnitude()
+            } else {
+                x2_minus_p.magnitude()
+            }
+        } else if x1_positive {
+            x1_minus_p.magnitude()
+        } else if x2_positive {
+            x2_minus_p.magnitude()
         } else {
             // No positive solution, return delta_linear as fallback
             delta_linear
-        }
+        })
     }
 
     /// Solves the torus invariant equation to calculate token output amount
     /// Based on the logic from the whitepaper: update xi to xi + d, then solve for xj
     /// that satisfies the global invariant while keeping all other asset balances the same
-    /// Uses proper Newton's method to solve the quartic equation in xj
+    /// Uses Newton's method with a closed-form derivative (see
+    /// `calculate_invariant_derivative`) to solve the quartic equation in xj.
+    /// Each step is the full Newton step (the step size is not damped); only
+    /// the resulting xj is clamped to [1, 2 × original reserve]
     /// All values are expected to be in Q96X48 fixed-point format
     pub fn solveTorusInvariant(
         &self,
@@ -359,30 +560,46 @@ impl OrbitalHelper {
         token_out_index: U144,
         amount_in_after_fee: U144,
         total_reserves: Vec<U144>,
-    ) -> U144 {
-        let sqrt_n = Self::sqrt_Q96X48(Self::convert_to_Q96X48(U144::from(5)));
-        
+        n: U144,
+    ) -> Result<U144, OrbitalError> {
+        if total_reserves.len() != n.as_limbs()[0] as usize {
+            return Err(OrbitalError::invalid_length());
+        }
+        let token_in_idx = token_in_index.as_limbs()[0] as usize;
+        let token_out_idx = token_out_index.as_limbs()[0] as usize;
+        if token_in_idx >= total_reserves.len() || token_out_idx >= total_reserves.len() {
+            return Err(OrbitalError::invalid_index());
+        }
+        let n_q = Self::convert_to_Q96X48(n);
+        let sqrt_n = Self::sqrt_Q96X48(n_q);
+
         // Starting from valid reserve state, update xi to xi + d
         let mut updated_total_reserves = total_reserves.clone();
-        updated_total_reserves[token_in_index.as_limbs()[0] as usize] = 
-            Self::add_Q96X48(total_reserves[token_in_index.as_limbs()[0] as usize], amount_in_after_fee);
-        
-        // Now solve for xj using Newton's method
-        let token_out_reserve = total_reserves[token_out_index.as_limbs()[0] as usize];
+        updated_total_reserves[token_in_idx] =
+            Self::add_Q96X48(total_reserves[token_in_idx], amount_in_after_fee)?;
+
+        // Now solve for xj using Newton's method. token_out_index is already
+        // bounds-checked above, so calculate_invariant_error (the only other
+        // place that indexes by it) doesn't need to repeat the check.
+        let token_out_reserve = total_reserves[token_out_idx];
         // Better initial guess: for stablecoin swaps, output ≈ input, so xj ≈ original_reserve - amount_in
         // This gives us a much better starting point for Newton's method
         let mut x_j = if token_out_reserve > amount_in_after_fee {
-            Self::sub_Q96X48(token_out_reserve, amount_in_after_fee)
+            Self::sub_Q96X48(token_out_reserve, amount_in_after_fee)?
         } else {
-            Self::div_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2))) // Fallback to 50% if not enough reserve
+            Self::div_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2)))? // Fallback to 50% if not enough reserve
         };
         let tolerance = Self::convert_to_Q96X48(U144::from(1));
-        let epsilon = Self::convert_to_Q96X48(U144::from(1)); // Small value for numerical derivative
-        
-        // Newton's method to find xj that satisfies the invariant
+
+        // Newton's method (undamped steps), using the closed-form derivative from
+        // `calculate_invariant_derivative` instead of a finite-difference
+        // estimate: each iteration now costs one residual evaluation (which
+        // already computes √variance and Σx_i as a side effect) plus a cheap
+        // analytic derivative, instead of two full residual evaluations, and
+        // converges quadratically so ~3-5 iterations suffice in practice.
         for _iteration in 0..20 {
             // Calculate f(xj) = target_r_int_squared - current_r_int_squared
-            let f_value = Self::calculate_invariant_error(
+            let (f_value, sqrt_variance, sum_total) = Self::calculate_invariant_error(
                 x_j,
                 &updated_total_reserves,
                 sum_interior_reserves,
@@ -391,89 +608,119 @@ impl OrbitalHelper {
                 boundary_total_k_bound,
                 token_out_index,
                 sqrt_n,
-            );
-            
+                n_q,
+            )?;
+
             // Check convergence
-            let abs_f_value = if f_value > U144::ZERO {
-                f_value
-            } else {
-                Self::sub_Q96X48(U144::ZERO, f_value) // abs(f_value)
-            };
-            
-            if abs_f_value <= tolerance {
+            let abs_f_value = f_value.abs();
+
+            if abs_f_value.magnitude() <= tolerance {
                 break;
             }
-            
-            // Calculate f'(xj) using numerical differentiation
-            let x_j_plus_epsilon = Self::add_Q96X48(x_j, epsilon);
-            let f_prime_value = Self::calculate_invariant_error(
-                x_j_plus_epsilon,
-                &updated_total_reserves,
-                sum_interior_reserves,
-                interior_consolidated_radius,
+
+            // Calculate f'(xj) analytically from the same √variance and Σx_i
+            // the residual evaluation above already produced.
+            let derivative = Self::calculate_invariant_derivative(
+                x_j,
+                sqrt_variance,
+                sum_total,
                 boundary_consolidated_radius,
-                boundary_total_k_bound,
-                token_out_index,
-                sqrt_n,
-            );
-            
-            // Calculate derivative: (f(x + ε) - f(x)) / ε
-            let derivative = Self::div_Q96X48(
-                Self::sub_Q96X48(f_prime_value, f_value),
-                epsilon
-            );
-            
-            // Avoid division by zero
-            if derivative == U144::ZERO {
-                break;
+                n_q,
+            )?;
+
+            // No real solution found: the residual stopped moving before convergence
+            if derivative == SignedQ96X48::ZERO {
+                return Err(OrbitalError::no_real_root());
             }
-            
+
             // Newton's update: xj = xj - f(xj) / f'(xj)
-            let update = Self::div_Q96X48(f_value, derivative);
-            x_j = Self::sub_Q96X48(x_j, update);
-            
+            let update = (f_value / derivative)?;
+            let x_j_signed = (SignedQ96X48::from_unsigned(x_j) - update)?;
+            x_j = x_j_signed.to_unsigned_saturating();
+
             // Ensure xj stays within valid bounds (positive and reasonable)
-            if x_j > Self::mul_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2))) {
-                x_j = Self::mul_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2))); // Max 2x original reserve
+            if x_j > Self::mul_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2)))? {
+                x_j = Self::mul_Q96X48(token_out_reserve, Self::convert_to_Q96X48(U144::from(2)))?; // Max 2x original reserve
             }
-            
+
             if x_j < Self::convert_to_Q96X48(U144::from(1)) {
                 x_j = Self::convert_to_Q96X48(U144::from(1)); // Minimum positive value
             }
         }
-        
+
         // Calculate amount_out = original_reserve - final_xj
-        if token_out_reserve > x_j {
-            Self::sub_Q96X48(token_out_reserve, x_j)
+        Ok(if token_out_reserve > x_j {
+            Self::sub_Q96X48(token_out_reserve, x_j)?
         } else {
             U144::ZERO // Safety check
-        }
+        })
     }
 }
 
 // Private helper functions for OrbitalHelper
 impl OrbitalHelper {
-    // Helper function to calculate variance term from reserves
-    fn calculate_variance_term(reserves: &[U144]) -> U144 {
-        let n = Self::convert_to_Q96X48(U144::from(5));
-        
+    // Narrows a 512-bit intermediate back down to U144, range-checking against
+    // 2^144 - 1 first so an out-of-range result reverts with a typed error
+    // instead of truncating.
+    fn narrow_u512_to_u144(value: U512) -> Result<U144, OrbitalError> {
+        let max_u144 = (U512::from(1u8) << 144) - U512::from(1u8);
+        if value > max_u144 {
+            return Err(OrbitalError::overflow());
+        }
+        // U144 is represented internally as [u64; 3], so we take the first 2.25 u64s
+        let limbs = value.as_limbs();
+        let low = limbs[0];
+        let mid = limbs[1];
+        let high = limbs[2] & 0xFFFF; // Only take lower 16 bits of the third limb (144 - 128 = 16)
+        Ok(U144::from_limbs([low, mid, high]))
+    }
+
+    // Narrows a U256 intermediate (used by sqrt_Q96X48, whose radicand never
+    // exceeds 192 bits) back down to U144.
+    fn narrow_u256_to_u144(value: U256) -> U144 {
+        let limbs = value.as_limbs();
+        let low = limbs[0];
+        let mid = limbs[1];
+        let high = limbs[2] & 0xFFFF; // only lower 16 bits for U144
+        U144::from_limbs([low, mid, high])
+    }
+
+    // Small integer power for U512, used to form g^(k-1) inside the guarded
+    // Newton recurrence in nth_root_Q96X48. k is always small (cube/quartic
+    // roots), so a plain loop is cheaper than a general-purpose pow.
+    fn u512_pow(base: U512, exponent: u32) -> U512 {
+        let mut result = U512::from(1u8);
+        for _ in 0..exponent {
+            result *= base;
+        }
+        result
+    }
+
+    // Helper function to calculate variance term from reserves. Also returns
+    // the raw reserve sum Σx_i, which the analytic derivative in
+    // `calculate_invariant_derivative` needs and would otherwise have to
+    // recompute from scratch.
+    fn calculate_variance_term(reserves: &[U144], n_q: U144) -> Result<(U144, U144), OrbitalError> {
         let mut sum_total = U144::ZERO;
         let mut sum_squares = U144::ZERO;
-        
+
         for &reserve in reserves {
-            sum_total = Self::add_Q96X48(sum_total, reserve);
-            let squared = Self::mul_Q96X48(reserve, reserve);
-            sum_squares = Self::add_Q96X48(sum_squares, squared);
+            sum_total = Self::add_Q96X48(sum_total, reserve)?;
+            let squared = Self::mul_Q96X48(reserve, reserve)?;
+            sum_squares = Self::add_Q96X48(sum_squares, squared)?;
         }
-        
+
         // Calculate √(Σx²_total_i - 1/n(Σx_total_i)²)
-        let sum_total_squared = Self::mul_Q96X48(sum_total, sum_total);
-        let one_over_n_sum_squared = Self::div_Q96X48(sum_total_squared, n);
-        let variance_inner = Self::sub_Q96X48(sum_squares, one_over_n_sum_squared);
-        Self::sqrt_Q96X48(variance_inner)
+        let sum_total_squared = Self::mul_Q96X48(sum_total, sum_total)?;
+        let one_over_n_sum_squared = Self::div_Q96X48(sum_total_squared, n_q)?;
+        let variance_inner = Self::sub_Q96X48(sum_squares, one_over_n_sum_squared)?;
+        Ok((Self::sqrt_Q96X48(variance_inner), sum_total))
     }
 
-    // Helper function to calculate the invariant error f(xj) = target_r_int_squared - current_r_int_squared
+    // Helper function to calculate the invariant error f(xj) = target_r_int_squared - current_r_int_squared.
+    // Also returns (√variance, Σx_i) of the boundary reserves at this xj so the
+    // caller can feed them straight into `calculate_invariant_derivative`
+    // instead of recomputing the variance term a second time.
     fn calculate_invariant_error(
         x_j: U144,
         updated_total_reserves: &Vec<U144>,
@@ -483,35 +730,200 @@ impl OrbitalHelper {
         boundary_total_k_bound: U144,
         token_out_index: U144,
         sqrt_n: U144,
-    ) -> U144 {
+        n_q: U144,
+    ) -> Result<(SignedQ96X48, U144, U144), OrbitalError> {
         // Create reserves with the current xj guess
         let mut current_reserves = updated_total_reserves.clone();
         current_reserves[token_out_index.as_limbs()[0] as usize] = x_j;
-        
+
         // Calculate variance term using helper function
-        let sqrt_variance = Self::calculate_variance_term(&current_reserves);
-        
+        let (sqrt_variance, sum_total) = Self::calculate_variance_term(&current_reserves, n_q)?;
+
         // Calculate second term: (√variance - boundary_consolidated_radius)²
-        let second_term_diff = Self::sub_Q96X48(sqrt_variance, boundary_consolidated_radius);
-        let second_term_squared = Self::mul_Q96X48(second_term_diff, second_term_diff);
-        
+        let second_term_diff = SignedQ96X48::diff(sqrt_variance, boundary_consolidated_radius)?;
+        let second_term_squared = (second_term_diff * second_term_diff)?;
+
         // Calculate first term: (1/√n * Σ(x_int_i) - k_bound - r_int√n)²
         // Note: sum_interior_reserves remains unchanged as per whitepaper logic
-        let scaled_interior_sum = Self::div_Q96X48(sum_interior_reserves, sqrt_n);
-        let r_int_sqrt_n = Self::mul_Q96X48(interior_consolidated_radius, sqrt_n);
-        let first_term_inner = Self::sub_Q96X48(
-            Self::sub_Q96X48(scaled_interior_sum, boundary_total_k_bound),
-            r_int_sqrt_n
-        );
-        let first_term_squared = Self::mul_Q96X48(first_term_inner, first_term_inner);
-        
+        let scaled_interior_sum = Self::div_Q96X48(sum_interior_reserves, sqrt_n)?;
+        let r_int_sqrt_n = Self::mul_Q96X48(interior_consolidated_radius, sqrt_n)?;
+        let first_term_inner = (SignedQ96X48::diff(scaled_interior_sum, boundary_total_k_bound)?
+            - SignedQ96X48::from_unsigned(r_int_sqrt_n))?;
+        let first_term_squared = (first_term_inner * first_term_inner)?;
+
         // Calculate target r²_int
-        let target_r_int_squared = Self::add_Q96X48(first_term_squared, second_term_squared);
-        
+        let target_r_int_squared = (first_term_squared + second_term_squared)?;
+
         // Calculate current r²_int from interior_consolidated_radius
-        let current_r_int_squared = Self::mul_Q96X48(interior_consolidated_radius, interior_consolidated_radius);
-        
+        let current_r_int_squared = SignedQ96X48::from_unsigned(Self::mul_Q96X48(
+            interior_consolidated_radius,
+            interior_consolidated_radius,
+        )?);
+
         // Return f(xj) = target_r_int_squared - current_r_int_squared
-        Self::sub_Q96X48(target_r_int_squared, current_r_int_squared)
+        let f_value = (target_r_int_squared - current_r_int_squared)?;
+        Ok((f_value, sqrt_variance, sum_total))
+    }
+
+    // Analytic derivative of `calculate_invariant_error` with respect to xj.
+    // Only the boundary variance term `√(Σx² - (Σx)²/n)` depends on xj (the
+    // interior term and r²_int are constant w.r.t. it), and
+    // d/dxj √(Σx² - (Σx)²/n) = (x_j - (Σx)/n) / √variance, so by the chain
+    // rule on (√variance - boundary_consolidated_radius)²:
+    //   f'(xj) = 2 * (√variance - boundary_consolidated_radius) * (x_j - (Σx)/n) / √variance
+    // letting the Newton loop take one analytic derivative instead of a
+    // second full residual evaluation.
+    fn calculate_invariant_derivative(
+        x_j: U144,
+        sqrt_variance: U144,
+        sum_total: U144,
+        boundary_consolidated_radius: U144,
+        n_q: U144,
+    ) -> Result<SignedQ96X48, OrbitalError> {
+        if sqrt_variance == U144::ZERO {
+            return Err(OrbitalError::division_by_zero());
+        }
+        let mean = Self::div_Q96X48(sum_total, n_q)?;
+        let x_minus_mean = SignedQ96X48::diff(x_j, mean)?;
+        let s_minus_r = SignedQ96X48::diff(sqrt_variance, boundary_consolidated_radius)?;
+        let two_signed = SignedQ96X48::from_unsigned(Self::convert_to_Q96X48(U144::from(2)));
+        let numerator = ((two_signed * s_minus_r)? * x_minus_mean)?;
+        numerator / SignedQ96X48::from_unsigned(sqrt_variance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_q96x48_matches_hand_computed_value() {
+        // 100 * 5 / 20 = 25, with no *2^48 rescale since the two opposing
+        // shifts from mul_Q96X48-then-div_Q96X48 cancel out exactly.
+        let result = OrbitalHelper::mul_div_Q96X48(
+            U144::from(100u64),
+            U144::from(5u64),
+            U144::from(20u64),
+        )
+        .unwrap();
+        assert_eq!(result, U144::from(25u64));
+    }
+
+    #[test]
+    fn calculate_radius_n_four_is_exact() {
+        // n = 4 gives sqrt(n) = 2 exactly, so every step (inv_sqrt_n = 1/2,
+        // denominator = 1/2, reserve / denominator = reserve * 2) is exact
+        // Q96X48 arithmetic with no rounding to obscure a sign/shift bug.
+        let reserve = OrbitalHelper::convert_to_Q96X48(U144::from(10u64));
+        let n = U144::from(4u64);
+        let radius = OrbitalHelper::calculate_radius(reserve, n).unwrap();
+        assert_eq!(radius, OrbitalHelper::convert_to_Q96X48(U144::from(20u64)));
+    }
+
+    #[test]
+    fn signed_q96x48_add_same_sign() {
+        let a = SignedQ96X48::from_magnitude(U144::from(3u64), true);
+        let b = SignedQ96X48::from_magnitude(U144::from(4u64), true);
+        let sum = (a + b).unwrap();
+        assert_eq!(sum, SignedQ96X48::from_magnitude(U144::from(7u64), true));
+    }
+
+    #[test]
+    fn signed_q96x48_add_opposite_sign_left_magnitude_wins() {
+        let a = SignedQ96X48::from_magnitude(U144::from(10u64), false);
+        let b = SignedQ96X48::from_magnitude(U144::from(4u64), true);
+        let sum = (a + b).unwrap();
+        assert_eq!(sum, SignedQ96X48::from_magnitude(U144::from(6u64), false));
+    }
+
+    #[test]
+    fn signed_q96x48_add_opposite_sign_right_magnitude_wins() {
+        let a = SignedQ96X48::from_magnitude(U144::from(4u64), false);
+        let b = SignedQ96X48::from_magnitude(U144::from(10u64), true);
+        let sum = (a + b).unwrap();
+        assert_eq!(sum, SignedQ96X48::from_magnitude(U144::from(6u64), true));
+    }
+
+    #[test]
+    fn signed_q96x48_sub_is_add_of_negation() {
+        let a = SignedQ96X48::from_magnitude(U144::from(5u64), false);
+        let b = SignedQ96X48::from_magnitude(U144::from(8u64), false);
+        let diff = (a - b).unwrap();
+        assert_eq!(diff, SignedQ96X48::from_magnitude(U144::from(3u64), true));
+    }
+
+    #[test]
+    fn signed_q96x48_partial_ord_crosses_sign() {
+        let negative = SignedQ96X48::from_magnitude(U144::from(1000u64), true);
+        let positive = SignedQ96X48::from_magnitude(U144::from(1u64), false);
+        assert!(negative < positive);
+    }
+
+    #[test]
+    fn sqrt_q96x48_perfect_square() {
+        let four = OrbitalHelper::convert_to_Q96X48(U144::from(4));
+        let two = OrbitalHelper::convert_to_Q96X48(U144::from(2));
+        assert_eq!(OrbitalHelper::sqrt_Q96X48(four), two);
+    }
+
+    #[test]
+    fn sqrt_q96x48_zero() {
+        assert_eq!(OrbitalHelper::sqrt_Q96X48(U144::ZERO), U144::ZERO);
+    }
+
+    #[test]
+    fn sqrt_round_q96x48_matches_floor_for_perfect_square() {
+        let nine = OrbitalHelper::convert_to_Q96X48(U144::from(9));
+        let three = OrbitalHelper::convert_to_Q96X48(U144::from(3));
+        assert_eq!(OrbitalHelper::sqrt_round_Q96X48(nine), three);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sqrt_round_q96x48_rounds_up_past_the_midpoint() {
+        // sqrt(2) in Q96X48: m is just past floor_root² + floor_root, so the
+        // rounded result is one ULP above the floored result.
+        let two = OrbitalHelper::convert_to_Q96X48(U144::from(2));
+        let floor = OrbitalHelper::sqrt_Q96X48(two);
+        assert_eq!(floor, U144::from(398065729532860u64));
+        assert_eq!(
+            OrbitalHelper::sqrt_round_Q96X48(two),
+            U144::from(398065729532861u64)
+        );
+    }
+
+    #[test]
+    fn sqrt_round_q96x48_rounds_down_before_the_midpoint() {
+        // sqrt(10) in Q96X48: m falls short of floor_root² + floor_root, so
+        // rounding and flooring agree.
+        let ten = OrbitalHelper::convert_to_Q96X48(U144::from(10));
+        let floor = OrbitalHelper::sqrt_Q96X48(ten);
+        assert_eq!(floor, U144::from(890102030748522u64));
+        assert_eq!(OrbitalHelper::sqrt_round_Q96X48(ten), floor);
+    }
+
+    #[test]
+    fn nth_root_q96x48_perfect_cube() {
+        let eight = OrbitalHelper::convert_to_Q96X48(U144::from(8));
+        let two = OrbitalHelper::convert_to_Q96X48(U144::from(2));
+        assert_eq!(OrbitalHelper::nth_root_Q96X48(eight, 3).unwrap(), two);
+    }
+
+    #[test]
+    fn nth_root_q96x48_perfect_fourth_power() {
+        let sixteen = OrbitalHelper::convert_to_Q96X48(U144::from(16));
+        let two = OrbitalHelper::convert_to_Q96X48(U144::from(2));
+        assert_eq!(OrbitalHelper::nth_root_Q96X48(sixteen, 4).unwrap(), two);
+    }
+
+    #[test]
+    fn nth_root_q96x48_k_one_returns_input() {
+        let five = OrbitalHelper::convert_to_Q96X48(U144::from(5));
+        assert_eq!(OrbitalHelper::nth_root_Q96X48(five, 1).unwrap(), five);
+    }
+
+    #[test]
+    fn nth_root_q96x48_zero_k_is_an_error() {
+        let five = OrbitalHelper::convert_to_Q96X48(U144::from(5));
+        assert!(OrbitalHelper::nth_root_Q96X48(five, 0).is_err());
+    }
+}